@@ -1,3 +1,4 @@
+use core::convert::TryFrom;
 use core::str::FromStr;
 use eyre::{eyre, Report as Error};
 use ibc_relayer::keyring::{HDPath, KeyEntry, KeyFile};
@@ -9,6 +10,7 @@ use std::str;
 use toml;
 use tracing::{debug, trace};
 
+use super::denom::{DecimalAmount, DenomRegistry};
 use super::id::ChainId;
 use super::util;
 use super::wallet::{Wallet, WalletAddress, WalletId};
@@ -29,6 +31,8 @@ pub struct ChainCommand {
     pub grpc_port: u16,
 
     pub p2p_port: u16,
+
+    pub denom_registry: DenomRegistry,
 }
 
 impl ChainCommand {
@@ -47,6 +51,7 @@ impl ChainCommand {
             rpc_port,
             grpc_port,
             p2p_port,
+            denom_registry: DenomRegistry::default(),
         }
     }
 
@@ -169,14 +174,18 @@ impl ChainCommand {
     pub fn add_genesis_account(
         &self,
         wallet: &WalletAddress,
-        amounts: &[(&str, u64)],
+        amounts: &[(&str, &str)],
     ) -> Result<(), Error> {
-        let amounts_str = itertools::join(
-            amounts
-                .iter()
-                .map(|(denom, amount)| format!("{}{}", amount, denom)),
-            ",",
-        );
+        let base_amounts = amounts
+            .iter()
+            .map(|(denom, amount)| {
+                let amount = DecimalAmount::try_from((*denom, *amount))?;
+                let base_units = self.denom_registry.to_base_units(&amount)?;
+                Ok(format!("{}{}", base_units, denom))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let amounts_str = itertools::join(base_amounts, ",");
 
         self.exec(&[
             "--home",
@@ -193,9 +202,11 @@ impl ChainCommand {
         &self,
         wallet_id: &WalletId,
         denom: &str,
-        amount: u64,
+        amount: &str,
     ) -> Result<(), Error> {
-        let amount_str = format!("{}{}", amount, denom);
+        let amount = DecimalAmount::try_from((denom, amount))?;
+        let base_units = self.denom_registry.to_base_units(&amount)?;
+        let amount_str = format!("{}{}", base_units, denom);
 
         self.exec(&[
             "--home",
@@ -269,7 +280,11 @@ impl ChainCommand {
         Ok(ChildProcess::new(child))
     }
 
-    pub fn query_balance(&self, wallet_id: &WalletAddress, denom: &str) -> Result<u64, Error> {
+    pub fn query_balance(
+        &self,
+        wallet_id: &WalletAddress,
+        denom: &str,
+    ) -> Result<DecimalAmount, Error> {
         let res = self.exec(&[
             "--node",
             &self.rpc_listen_address(),
@@ -290,8 +305,8 @@ impl ChainCommand {
             .ok_or_else(|| eyre!("expected string field"))?
             .to_string();
 
-        let amount = u64::from_str(&amount_str)?;
+        let base_units = u64::from_str(&amount_str)?;
 
-        Ok(amount)
+        Ok(self.denom_registry.from_base_units(denom, base_units)?)
     }
 }
\ No newline at end of file
@@ -0,0 +1,143 @@
+use core::convert::TryFrom;
+use core::str::FromStr;
+use std::collections::HashMap;
+
+use eyre::{eyre, Report as Error};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+/// A human-readable amount together with its denomination, e.g. `1.5 uatom`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecimalAmount {
+    pub value: Decimal,
+    pub denom: String,
+}
+
+impl DecimalAmount {
+    pub fn new(denom: impl Into<String>, value: Decimal) -> Self {
+        Self {
+            denom: denom.into(),
+            value,
+        }
+    }
+}
+
+impl TryFrom<(&str, &str)> for DecimalAmount {
+    type Error = Error;
+
+    fn try_from((denom, value): (&str, &str)) -> Result<Self, Error> {
+        let value = Decimal::from_str(value)
+            .map_err(|e| eyre!("invalid decimal amount {:?}: {}", value, e))?;
+
+        Ok(DecimalAmount::new(denom, value))
+    }
+}
+
+/// Maps denominations to the exponent that scales their human-readable
+/// amount to base units, e.g. `uatom` has exponent `6` since `1 atom ==
+/// 1_000_000 uatom`.
+#[derive(Clone, Debug)]
+pub struct DenomRegistry {
+    exponents: HashMap<String, u32>,
+}
+
+impl DenomRegistry {
+    pub fn register(&mut self, denom: impl Into<String>, exponent: u32) {
+        self.exponents.insert(denom.into(), exponent);
+    }
+
+    pub fn exponent(&self, denom: &str) -> Result<u32, Error> {
+        self.exponents
+            .get(denom)
+            .copied()
+            .ok_or_else(|| eyre!("no registered exponent for denomination {:?}", denom))
+    }
+
+    /// Scale a human-readable `amount` up to base units, e.g. `1.5 uatom` to
+    /// `1500000`. Returns an `Error` instead of wrapping if the scaled amount
+    /// overflows `u64`, and an `Error` instead of truncating if `amount` has
+    /// more precision than the denomination's exponent supports (e.g.
+    /// `1.5000001 uatom` at exponent `6`).
+    pub fn to_base_units(&self, amount: &DecimalAmount) -> Result<u64, Error> {
+        let exponent = self.exponent(&amount.denom)?;
+        let base = base_of(exponent)?;
+
+        let scaled = amount
+            .value
+            .checked_mul(base)
+            .ok_or_else(|| eyre!("overflow scaling {:?} to base units", amount))?;
+
+        if !scaled.fract().is_zero() {
+            return Err(eyre!(
+                "{:?} has more precision than denomination exponent {} supports",
+                amount,
+                exponent
+            ));
+        }
+
+        scaled
+            .to_u64()
+            .ok_or_else(|| eyre!("{:?} does not fit in u64 base units", amount))
+    }
+
+    /// Scale `base_units` of `denom` down to a human-readable amount, e.g.
+    /// `1500000` of `uatom` to `1.5`.
+    pub fn from_base_units(&self, denom: &str, base_units: u64) -> Result<DecimalAmount, Error> {
+        let exponent = self.exponent(denom)?;
+        let base = base_of(exponent)?;
+
+        let value = Decimal::from(base_units)
+            .checked_div(base)
+            .ok_or_else(|| eyre!("overflow converting {} base units of {:?}", base_units, denom))?;
+
+        Ok(DecimalAmount::new(denom, value))
+    }
+}
+
+impl Default for DenomRegistry {
+    /// A registry pre-populated with the denominations of the chains the
+    /// relayer tests commonly spin up.
+    fn default() -> Self {
+        let mut registry = Self {
+            exponents: HashMap::new(),
+        };
+
+        registry.register("uatom", 6);
+        registry.register("stake", 6);
+
+        registry
+    }
+}
+
+fn base_of(exponent: u32) -> Result<Decimal, Error> {
+    let base = 10u64
+        .checked_pow(exponent)
+        .ok_or_else(|| eyre!("exponent {} overflows u64", exponent))?;
+
+    Ok(Decimal::from(base))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_base_units_rejects_sub_exponent_precision() {
+        let registry = DenomRegistry::default();
+        let amount = DecimalAmount::try_from(("uatom", "1.5000001")).unwrap();
+
+        let result = registry.to_base_units(&amount);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn to_base_units_accepts_exact_exponent_precision() {
+        let registry = DenomRegistry::default();
+        let amount = DecimalAmount::try_from(("uatom", "1.5")).unwrap();
+
+        let base_units = registry.to_base_units(&amount).unwrap();
+
+        assert_eq!(base_units, 1_500_000);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod command;
+pub mod denom;
+pub mod id;
+pub mod util;
+pub mod wallet;
@@ -0,0 +1,42 @@
+use anomaly::{BoxError, Context};
+use thiserror::Error;
+
+pub type Error = anomaly::Error<Kind>;
+
+#[derive(Clone, Debug, Error)]
+pub enum Kind {
+    /// Mnemonic phrase could not be parsed
+    #[error("invalid mnemonic")]
+    InvalidMnemonic,
+
+    /// Private key could not be derived
+    #[error("error deriving private key")]
+    PrivateKey,
+
+    /// No key entry was found for the given address
+    #[error("invalid key")]
+    InvalidKey,
+
+    /// Reading or writing a keystore file on disk failed
+    #[error("I/O error")]
+    Io,
+
+    /// A keystore file could not be encrypted or decrypted
+    #[error("keystore crypto error")]
+    Crypto,
+
+    /// The password used to decrypt a keystore file does not match its MAC
+    #[error("invalid keystore password")]
+    InvalidPassword,
+
+    /// An HD path could not be built, e.g. an unhardened index did not fit
+    /// in the 31 bits BIP-32 allows
+    #[error("invalid HD path")]
+    InvalidHdPath,
+}
+
+impl Kind {
+    pub fn context(self, source: impl Into<BoxError>) -> Context<Self> {
+        Context::new(self, Some(source.into()))
+    }
+}
@@ -0,0 +1,174 @@
+//! Web3 Secret Storage (V3) encrypted keystore, compatible with the format
+//! produced and consumed by `eth-keystore`.
+
+use aes::Aes128;
+use ctr::cipher::{NewCipher, StreamCipher};
+use ctr::Ctr128BE;
+use rand::{rngs::OsRng, RngCore};
+use scrypt::{scrypt, Params as ScryptParams};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+use subtle::ConstantTimeEq;
+
+use crate::keyring::errors::{Error, Kind};
+
+type Aes128Ctr = Ctr128BE<Aes128>;
+
+const SCRYPT_LOG_N: u8 = 13; // n = 2^13 = 8192
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+const DK_LEN: usize = 32;
+const SALT_LEN: usize = 32;
+const IV_LEN: usize = 16;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CipherParams {
+    pub iv: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct KdfParams {
+    pub dklen: usize,
+    pub n: u32,
+    pub p: u32,
+    pub r: u32,
+    pub salt: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CryptoJson {
+    pub cipher: String,
+    pub cipherparams: CipherParams,
+    pub ciphertext: String,
+    pub kdf: String,
+    pub kdfparams: KdfParams,
+    pub mac: String,
+}
+
+/// A Web3 Secret Storage V3 keystore file, as written to and read from disk
+/// by the `StoreBackend::EncryptedFile` backend.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct KeystoreFile {
+    pub crypto: CryptoJson,
+    pub address: String,
+    pub version: u32,
+}
+
+/// Encrypt `private_key` under `password`, producing a keystore file for `address`.
+pub fn encrypt(private_key: &[u8], password: &str, address: &[u8]) -> Result<KeystoreFile, Error> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+
+    let mut iv = [0u8; IV_LEN];
+    OsRng.fill_bytes(&mut iv);
+
+    let derived_key = derive_key(password, &salt, SCRYPT_LOG_N, SCRYPT_R, SCRYPT_P)?;
+
+    let mut ciphertext = private_key.to_vec();
+    let mut cipher = Aes128Ctr::new(derived_key[..16].into(), iv[..].into());
+    cipher.apply_keystream(&mut ciphertext);
+
+    let mac = compute_mac(&derived_key[16..32], &ciphertext);
+
+    Ok(KeystoreFile {
+        crypto: CryptoJson {
+            cipher: "aes-128-ctr".to_string(),
+            cipherparams: CipherParams {
+                iv: hex::encode(iv),
+            },
+            ciphertext: hex::encode(&ciphertext),
+            kdf: "scrypt".to_string(),
+            kdfparams: KdfParams {
+                dklen: DK_LEN,
+                n: 1 << SCRYPT_LOG_N,
+                p: SCRYPT_P,
+                r: SCRYPT_R,
+                salt: hex::encode(salt),
+            },
+            mac: hex::encode(mac),
+        },
+        address: hex::encode(address),
+        version: 3,
+    })
+}
+
+/// Decrypt `file` under `password`, returning the raw private key bytes.
+///
+/// Returns `Kind::InvalidPassword` if the recomputed MAC does not match the
+/// one stored in the keystore file.
+pub fn decrypt(file: &KeystoreFile, password: &str) -> Result<Vec<u8>, Error> {
+    if file.crypto.kdf != "scrypt" {
+        return Err(Kind::Crypto.context(format!("unsupported kdf: {}", file.crypto.kdf)).into());
+    }
+
+    let salt = hex::decode(&file.crypto.kdfparams.salt).map_err(|e| Kind::Crypto.context(e))?;
+    let log_n = (file.crypto.kdfparams.n as f64).log2().round() as u8;
+    let derived_key = derive_key(
+        password,
+        &salt,
+        log_n,
+        file.crypto.kdfparams.r,
+        file.crypto.kdfparams.p,
+    )?;
+
+    let ciphertext = hex::decode(&file.crypto.ciphertext).map_err(|e| Kind::Crypto.context(e))?;
+    let mac = compute_mac(&derived_key[16..32], &ciphertext);
+    let expected_mac = hex::decode(&file.crypto.mac).map_err(|e| Kind::Crypto.context(e))?;
+
+    // Compare in constant time: `mac` is derived from the password-derived key,
+    // so a timing side channel here would leak information about the password.
+    if mac.len() != expected_mac.len() || mac.ct_eq(&expected_mac).unwrap_u8() != 1 {
+        return Err(Kind::InvalidPassword.into());
+    }
+
+    let iv = hex::decode(&file.crypto.cipherparams.iv).map_err(|e| Kind::Crypto.context(e))?;
+    let mut plaintext = ciphertext;
+    let mut cipher = Aes128Ctr::new(derived_key[..16].into(), iv[..].into());
+    cipher.apply_keystream(&mut plaintext);
+
+    Ok(plaintext)
+}
+
+fn derive_key(password: &str, salt: &[u8], log_n: u8, r: u32, p: u32) -> Result<[u8; DK_LEN], Error> {
+    let params = ScryptParams::new(log_n, r, p).map_err(|e| Kind::Crypto.context(e))?;
+    let mut derived_key = [0u8; DK_LEN];
+    scrypt(password.as_bytes(), salt, &params, &mut derived_key)
+        .map_err(|e| Kind::Crypto.context(e))?;
+    Ok(derived_key)
+}
+
+fn compute_mac(key_half: &[u8], ciphertext: &[u8]) -> Vec<u8> {
+    let mut hasher = Keccak256::new();
+    hasher.update(key_half);
+    hasher.update(ciphertext);
+    hasher.finalize().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRIVATE_KEY: [u8; 32] = [7u8; 32];
+    const ADDRESS: [u8; 20] = [1u8; 20];
+
+    #[test]
+    fn encrypt_decrypt_round_trip() {
+        let file = encrypt(&PRIVATE_KEY, "correct horse battery staple", &ADDRESS)
+            .expect("encryption should succeed");
+
+        let decrypted = decrypt(&file, "correct horse battery staple")
+            .expect("decryption with the right password should succeed");
+
+        assert_eq!(decrypted, PRIVATE_KEY);
+    }
+
+    #[test]
+    fn decrypt_rejects_wrong_password() {
+        let file = encrypt(&PRIVATE_KEY, "correct horse battery staple", &ADDRESS)
+            .expect("encryption should succeed");
+
+        let result = decrypt(&file, "wrong password");
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,11 @@
+//! Key management for signing transactions on behalf of the relayer.
+
+mod errors;
+mod keystore;
+mod store;
+
+pub use errors::{Error, Kind};
+pub use store::{
+    Address, KeyAlgorithm, KeyEntry, KeyRing, KeyRingOperations, MnemonicStrength, StoreBackend,
+    COSMOS_COIN_TYPE,
+};
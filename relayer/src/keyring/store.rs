@@ -1,129 +1,359 @@
 
 use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
 use k256::{
     ecdsa::{signature::Signer, signature::Verifier, Signature, SigningKey, VerifyKey},
     EncodedPoint, SecretKey,
 };
 use bitcoin_wallet::account::MasterAccount;
-use bitcoin_wallet::mnemonic::Mnemonic;
+use bitcoin_wallet::mnemonic::{Language, Mnemonic};
 use bitcoin::{
     network::constants::Network,
-    util::bip32::{DerivationPath, ExtendedPrivKey, ExtendedPubKey},
+    util::bip32::{ChainCode, ChildNumber, DerivationPath, ExtendedPrivKey, ExtendedPubKey, Fingerprint},
     PrivateKey,
 };
 use hdpath::StandardHDPath;
 use bitcoin::secp256k1::{All, Message, Secp256k1};
+use ed25519_dalek::{
+    Keypair as Ed25519Keypair, PublicKey as Ed25519PublicKey, SecretKey as Ed25519SecretKey,
+    Signature as Ed25519Signature, Signer as _, Verifier as _,
+};
+use rand::{rngs::OsRng, RngCore};
+use sha2::{Digest, Sha512};
 use std::convert::TryFrom;
+use std::ops::Range;
 use crate::keyring::errors::{Error, Kind};
+use crate::keyring::keystore::{self, KeystoreFile};
+
+/// Selects the signature scheme a `KeyEntry` is generated and signs under.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KeyAlgorithm {
+    /// secp256k1 with a RIPEMD160(SHA256(pubkey)) address, used by Cosmos SDK chains.
+    Secp256k1,
+    /// ed25519, used for consensus and some account keys on Tendermint chains.
+    Ed25519,
+}
+
+impl Default for KeyAlgorithm {
+    fn default() -> Self {
+        KeyAlgorithm::Secp256k1
+    }
+}
+
+const SECP256K1_TAG: u8 = 0;
+const ED25519_TAG: u8 = 1;
+
+/// Coin type of the default `m/44'/118'/0'/0/0` Cosmos HD path.
+pub const COSMOS_COIN_TYPE: u32 = 118;
+
+/// Entropy size, in bytes, used when generating a new BIP-39 mnemonic.
+#[derive(Copy, Clone, Debug)]
+pub enum MnemonicStrength {
+    /// 128-bit entropy, yielding a 12-word mnemonic.
+    Words12,
+    /// 256-bit entropy, yielding a 24-word mnemonic.
+    Words24,
+}
+
+impl MnemonicStrength {
+    fn entropy_len(self) -> usize {
+        match self {
+            MnemonicStrength::Words12 => 16,
+            MnemonicStrength::Words24 => 32,
+        }
+    }
+}
 
 pub type Address = Vec<u8>;
 
 pub enum KeyRing {
-    MemoryKeyStore { store: BTreeMap<Address, KeyEntry> }
+    MemoryKeyStore { store: BTreeMap<Address, KeyEntry> },
+    EncryptedFileKeyStore { dir: PathBuf, password: String },
 }
 
 pub enum StoreBackend {
-    Memory
+    Memory,
+    EncryptedFile { dir: PathBuf, password: String },
 }
 
 pub trait KeyRingOperations: Sized {
     fn init(backend: StoreBackend) -> KeyRing;
     fn add_from_mnemonic(&mut self, mnemonic_words: &str) -> Result<Address, Error>;
-    fn get(&self, address: Vec<u8>) -> Result<&KeyEntry, Error>;
-    fn insert(&mut self, addr: Vec<u8>, key: KeyEntry) -> Option<KeyEntry>;
-    fn sign(&self, signer: Vec<u8>, msg: Vec<u8>) -> Vec<u8>;
+    fn add_from_mnemonic_with_path(
+        &mut self,
+        mnemonic_words: &str,
+        hd_path: &StandardHDPath,
+        algorithm: KeyAlgorithm,
+    ) -> Result<Address, Error>;
+    fn derive_accounts(
+        &mut self,
+        mnemonic_words: &str,
+        account_range: Range<u32>,
+    ) -> Result<Vec<Address>, Error>;
+    fn generate(&mut self, strength: MnemonicStrength) -> Result<(String, Address), Error>;
+    fn add_from_brain(&mut self, passphrase: &str) -> Result<Address, Error>;
+    fn get(&self, address: Vec<u8>) -> Result<KeyEntry, Error>;
+    fn insert(&mut self, addr: Vec<u8>, key: KeyEntry) -> Result<Option<KeyEntry>, Error>;
+    fn sign(&self, signer: Vec<u8>, msg: Vec<u8>) -> Result<Vec<u8>, Error>;
+    fn verify(&self, signer: Address, msg: &[u8], sig: &[u8]) -> Result<(), Error>;
 }
 
-/// Key entry stores the Private Key and Public Key as well the address
+/// Key entry stores the Private Key and Public Key, from which the address is
+/// derived, for whichever `KeyAlgorithm` the key was generated under.
 #[derive(Clone, Debug)]
-pub struct KeyEntry {
-    /// Public key
-    pub public_key: ExtendedPubKey,
+pub enum KeyEntry {
+    Secp256k1 {
+        /// Public key
+        public_key: ExtendedPubKey,
 
-    /// Private key
-    pub private_key: ExtendedPrivKey,
+        /// Private key
+        private_key: ExtendedPrivKey,
+    },
+    Ed25519 {
+        /// Public key
+        public_key: [u8; 32],
+
+        /// Private key
+        private_key: [u8; 32],
+    },
+}
+
+impl KeyEntry {
+    /// The `KeyAlgorithm` this entry was generated under.
+    pub fn algorithm(&self) -> KeyAlgorithm {
+        match self {
+            KeyEntry::Secp256k1 { .. } => KeyAlgorithm::Secp256k1,
+            KeyEntry::Ed25519 { .. } => KeyAlgorithm::Ed25519,
+        }
+    }
+
+    /// Derive this entry's address, dispatching on its `KeyAlgorithm`.
+    pub fn get_address(&self) -> Address {
+        get_address(self)
+    }
 }
 
 impl KeyRingOperations for KeyRing {
 
-    /// Initialize a in memory key entry store
+    /// Initialize a key entry store backed by `backend`.
     fn init(backend: StoreBackend) -> KeyRing {
         match backend {
             StoreBackend::Memory => {
                 let store: BTreeMap<Address, KeyEntry> = BTreeMap::new();
                 KeyRing::MemoryKeyStore { store }
             }
+            StoreBackend::EncryptedFile { dir, password } => {
+                KeyRing::EncryptedFileKeyStore { dir, password }
+            }
         }
     }
 
-    /// Add a key entry in the store using a mnemonic.
+    /// Add a key entry in the store using a mnemonic, deriving a secp256k1
+    /// key at the default Cosmos HD path `m/44'/118'/0'/0/0`.
     fn add_from_mnemonic(&mut self, mnemonic_words: &str) -> Result<Address, Error> {
+        let hd_path = standard_hd_path(COSMOS_COIN_TYPE, 0, 0)?;
+        self.add_from_mnemonic_with_path(mnemonic_words, &hd_path, KeyAlgorithm::Secp256k1)
+    }
+
+    /// Add a key entry in the store using a mnemonic, deriving it at `hd_path`
+    /// under the given `algorithm`. This allows callers to override the coin
+    /// type (e.g. 60 for Ethereum, 529 for Secret Network), derive more than
+    /// one account from the same mnemonic, and serve chains whose keys are
+    /// ed25519 rather than secp256k1.
+    fn add_from_mnemonic_with_path(
+        &mut self,
+        mnemonic_words: &str,
+        hd_path: &StandardHDPath,
+        algorithm: KeyAlgorithm,
+    ) -> Result<Address, Error> {
 
         // Generate seed from mnemonic
         let mnemonic = Mnemonic::from_str(mnemonic_words).map_err(|e| Kind::InvalidMnemonic.context(e))?;
         let seed = mnemonic.to_seed(Some(""));
 
-        // Get Private Key from seed and standard derivation path
-        let hd_path = StandardHDPath::try_from("m/44'/118'/0'/0/0").unwrap();
-        let private_key = ExtendedPrivKey::new_master(Network::Bitcoin, &seed.0)
-            .and_then(|k| k.derive_priv(&Secp256k1::new(), &DerivationPath::from(hd_path))).map_err(|e| Kind::PrivateKey.context(e))?;
+        let key = match algorithm {
+            KeyAlgorithm::Secp256k1 => {
+                // Get Private Key from seed and the given derivation path
+                let private_key = ExtendedPrivKey::new_master(Network::Bitcoin, &seed.0)
+                    .and_then(|k| k.derive_priv(&Secp256k1::new(), &DerivationPath::from(hd_path.clone()))).map_err(|e| Kind::PrivateKey.context(e))?;
 
-        // Get Public Key from Private Key
-        let public_key = ExtendedPubKey::from_private(&Secp256k1::new(), &private_key);
+                // Get Public Key from Private Key
+                let public_key = ExtendedPubKey::from_private(&Secp256k1::new(), &private_key);
 
-        // Get address from the Public Key
-        let address = get_address(public_key);
-
-        let key = KeyEntry {
-            public_key,
-            private_key
+                KeyEntry::Secp256k1 { public_key, private_key }
+            }
+            KeyAlgorithm::Ed25519 => {
+                let (private_key, public_key) = derive_ed25519_keypair(&seed.0, hd_path)?;
+                KeyEntry::Ed25519 { public_key, private_key }
+            }
         };
 
-        self.insert(address.clone(), key);
+        let address = key.get_address();
+
+        self.insert(address.clone(), key)?;
 
         Ok(address)
     }
 
-    /// Return a key entry from a key name
-    fn get(&self, address: Vec<u8>) -> Result<&KeyEntry, Error> {
-        match &self {
+    /// Derive and add one key entry per account in `account_range`, varying
+    /// only the `index` field of the default Cosmos HD path (the hardened
+    /// `account'` field stays `0`) so a single mnemonic backs the same
+    /// sequence of addresses that Cosmos SDK keyrings and other wallets
+    /// derive for that mnemonic.
+    fn derive_accounts(
+        &mut self,
+        mnemonic_words: &str,
+        account_range: Range<u32>,
+    ) -> Result<Vec<Address>, Error> {
+        account_range
+            .map(|index| {
+                let hd_path = standard_hd_path(COSMOS_COIN_TYPE, 0, index)?;
+                self.add_from_mnemonic_with_path(mnemonic_words, &hd_path, KeyAlgorithm::Secp256k1)
+            })
+            .collect()
+    }
+
+    /// Generate a new BIP-39 mnemonic from CSPRNG entropy, add the key entry
+    /// it derives at the default Cosmos HD path, and return the mnemonic
+    /// phrase alongside the resulting address. The phrase is only ever
+    /// returned here and is not otherwise persisted, so callers must back it
+    /// up immediately.
+    fn generate(&mut self, strength: MnemonicStrength) -> Result<(String, Address), Error> {
+        let mut entropy = vec![0u8; strength.entropy_len()];
+        OsRng.fill_bytes(&mut entropy);
+
+        let mnemonic = Mnemonic::new(&entropy, Language::English).map_err(|e| Kind::InvalidMnemonic.context(e))?;
+        let phrase = mnemonic.to_string();
+
+        let address = self.add_from_mnemonic(&phrase)?;
+
+        Ok((phrase, address))
+    }
+
+    /// Deterministically derive a mnemonic from a passphrase and add the key
+    /// entry it derives at the default Cosmos HD path. This is meant for
+    /// reproducible test wallets, not for securing real funds: anyone who
+    /// learns the passphrase can recreate the same key.
+    fn add_from_brain(&mut self, passphrase: &str) -> Result<Address, Error> {
+        use crypto::digest::Digest;
+        use crypto::sha2::Sha256;
+
+        let mut hasher = Sha256::new();
+        hasher.input_str(passphrase);
+        let mut entropy = vec![0; hasher.output_bytes()];
+        hasher.result(&mut entropy);
+
+        let mnemonic = Mnemonic::new(&entropy, Language::English).map_err(|e| Kind::InvalidMnemonic.context(e))?;
+
+        self.add_from_mnemonic(&mnemonic.to_string())
+    }
+
+    /// Return the key entry stored for `address`, decrypting it from disk if the
+    /// store is backed by an encrypted file keystore.
+    fn get(&self, address: Vec<u8>) -> Result<KeyEntry, Error> {
+        match self {
             KeyRing::MemoryKeyStore { store: s } => {
-                if !s.contains_key(&address) {
-                    return Err(Kind::InvalidKey.into());
-                }
-                else {
-                    let key = s.get(&address);
-                    match key {
-                        Some(k) => Ok(k),
-                        None => Err(Kind::InvalidKey.into())
-                    }
-                }
+                s.get(&address).cloned().ok_or_else(|| Kind::InvalidKey.into())
+            }
+            KeyRing::EncryptedFileKeyStore { dir, password } => {
+                read_key_entry(&keystore_path(dir, &address), password)
             }
         }
     }
 
-    /// Insert an entry in the key store
-    fn insert(&mut self, addr: Vec<u8>, key: KeyEntry) -> Option<KeyEntry> {
+    /// Insert an entry in the key store, encrypting it to disk when the
+    /// store is backed by an encrypted file keystore. Returns an `Error`
+    /// rather than panicking if the encrypted file keystore cannot be
+    /// written, so a disk hiccup doesn't bring the relayer down.
+    fn insert(&mut self, addr: Vec<u8>, key: KeyEntry) -> Result<Option<KeyEntry>, Error> {
         match self {
             KeyRing::MemoryKeyStore { store: s} => {
-                let ke = s.insert(addr, key);
-                ke
+                Ok(s.insert(addr, key))
+            }
+            KeyRing::EncryptedFileKeyStore { dir, password } => {
+                let path = keystore_path(dir, &addr);
+                let previous = read_key_entry(&path, password).ok();
+
+                let raw = key_entry_to_raw_bytes(&key);
+                let file = keystore::encrypt(&raw, password, &addr)?;
+                let contents = serde_json::to_string(&file).map_err(|e| Kind::Io.context(e))?;
+
+                fs::create_dir_all(dir).map_err(|e| Kind::Io.context(e))?;
+                fs::write(&path, contents).map_err(|e| Kind::Io.context(e))?;
+
+                Ok(previous)
             }
         }
     }
 
     /// Sign a message
-    fn sign(&self, signer: Vec<u8>, msg: Vec<u8>) -> Vec<u8> {
-        let key = self.get(signer).unwrap();
-        let private_key_bytes = key.private_key.private_key.to_bytes();
-        let signing_key = SigningKey::new(private_key_bytes.as_slice()).unwrap();
-        let signature: Signature = signing_key.sign(&msg);
-        signature.as_ref().to_vec()
+    fn sign(&self, signer: Vec<u8>, msg: Vec<u8>) -> Result<Vec<u8>, Error> {
+        let key = self.get(signer)?;
+
+        match key {
+            KeyEntry::Secp256k1 { private_key, .. } => {
+                let private_key_bytes = private_key.private_key.to_bytes();
+                let signing_key = SigningKey::new(private_key_bytes.as_slice()).map_err(|e| Kind::PrivateKey.context(e))?;
+                let signature: Signature = signing_key.sign(&msg);
+                Ok(signature.as_ref().to_vec())
+            }
+            KeyEntry::Ed25519 { private_key, .. } => {
+                let keypair = ed25519_keypair(&private_key)?;
+                let signature: Ed25519Signature = keypair.sign(&msg);
+                Ok(signature.to_bytes().to_vec())
+            }
+        }
+    }
+
+    /// Verify that `sig` is a valid signature over `msg` by `signer`.
+    fn verify(&self, signer: Address, msg: &[u8], sig: &[u8]) -> Result<(), Error> {
+        let key = self.get(signer)?;
+
+        match key {
+            KeyEntry::Secp256k1 { private_key, .. } => {
+                let private_key_bytes = private_key.private_key.to_bytes();
+                let signing_key = SigningKey::new(private_key_bytes.as_slice()).map_err(|e| Kind::PrivateKey.context(e))?;
+                let verify_key: VerifyKey = signing_key.verify_key();
+
+                let signature = Signature::try_from(sig).map_err(|e| Kind::InvalidKey.context(e))?;
+                verify_key.verify(msg, &signature).map_err(|e| Kind::InvalidKey.context(e))
+            }
+            KeyEntry::Ed25519 { public_key, .. } => {
+                let public_key = Ed25519PublicKey::from_bytes(&public_key).map_err(|e| Kind::InvalidKey.context(e))?;
+                let signature = Ed25519Signature::try_from(sig).map_err(|e| Kind::InvalidKey.context(e))?;
+                public_key.verify(msg, &signature).map_err(|e| Kind::InvalidKey.context(e))
+            }
+        }
+    }
+}
+
+/// Largest value a non-hardened BIP-32 index can hold (`2^31 - 1`).
+const MAX_UNHARDENED_INDEX: u32 = (1 << 31) - 1;
+
+/// Build the BIP-44 HD path `m/44'/coin_type'/account'/0/index`.
+///
+/// Returns `Kind::InvalidHdPath` rather than panicking if `index` doesn't fit
+/// in a non-hardened BIP-32 index (`< 2^31`) or the path otherwise fails to parse.
+fn standard_hd_path(coin_type: u32, account: u32, index: u32) -> Result<StandardHDPath, Error> {
+    if index > MAX_UNHARDENED_INDEX {
+        return Err(Kind::InvalidHdPath.into());
+    }
+
+    let path = format!("m/44'/{}'/{}'/0/{}", coin_type, account, index);
+    StandardHDPath::try_from(path.as_str()).map_err(|e| Kind::InvalidHdPath.context(e).into())
+}
+
+/// Return a `KeyEntry`'s address, dispatching on its `KeyAlgorithm`.
+fn get_address(key: &KeyEntry) -> Address {
+    match key {
+        KeyEntry::Secp256k1 { public_key, .. } => secp256k1_address(*public_key),
+        KeyEntry::Ed25519 { public_key, .. } => ed25519_address(public_key),
     }
 }
 
-/// Return an address from a Public Key
-fn get_address(pk: ExtendedPubKey) -> Vec<u8> {
+/// Cosmos SDK address: RIPEMD160(SHA256(pubkey)).
+fn secp256k1_address(pk: ExtendedPubKey) -> Vec<u8> {
     use crypto::digest::Digest;
     use crypto::ripemd160::Ripemd160;
     use crypto::sha2::Sha256;
@@ -137,4 +367,267 @@ fn get_address(pk: ExtendedPubKey) -> Vec<u8> {
     let mut acct = vec![0; hash.output_bytes()];
     hash.result(&mut acct);
     acct.to_vec()
-}
\ No newline at end of file
+}
+
+/// Tendermint address: the first 20 bytes of SHA256(pubkey).
+fn ed25519_address(public_key: &[u8; 32]) -> Vec<u8> {
+    use crypto::digest::Digest;
+    use crypto::sha2::Sha256;
+
+    let mut sha256 = Sha256::new();
+    sha256.input(public_key);
+    let mut bytes = vec![0; sha256.output_bytes()];
+    sha256.result(&mut bytes);
+    bytes.truncate(20);
+    bytes
+}
+
+/// Build an ed25519 `Keypair` from a raw 32-byte secret scalar.
+fn ed25519_keypair(private_key: &[u8; 32]) -> Result<Ed25519Keypair, Error> {
+    let secret = Ed25519SecretKey::from_bytes(private_key).map_err(|e| Kind::PrivateKey.context(e))?;
+    let public = Ed25519PublicKey::from(&secret);
+    Ok(Ed25519Keypair { secret, public })
+}
+
+/// Derive an ed25519 keypair from a BIP-39 seed and HD path.
+///
+/// There is no ed25519 equivalent of BIP-32 in widespread use across Tendermint
+/// chains, so this hashes the seed together with the HD path to deterministically
+/// derive the key, rather than implementing hardened-only schemes like SLIP-0010.
+fn derive_ed25519_keypair(
+    seed: &[u8],
+    hd_path: &StandardHDPath,
+) -> Result<([u8; 32], [u8; 32]), Error> {
+    let mut hasher = Sha512::new();
+    hasher.update(seed);
+    hasher.update(hd_path.to_string().as_bytes());
+    let digest = hasher.finalize();
+
+    let mut private_key = [0u8; 32];
+    private_key.copy_from_slice(&digest[0..32]);
+
+    let keypair = ed25519_keypair(&private_key)?;
+
+    Ok((private_key, keypair.public.to_bytes()))
+}
+
+/// Path of the keystore file holding the encrypted key entry for `address` in `dir`.
+fn keystore_path(dir: &Path, address: &[u8]) -> PathBuf {
+    dir.join(format!("{}.json", hex::encode(address)))
+}
+
+/// Read and decrypt the keystore file at `path` under `password`.
+fn read_key_entry(path: &Path, password: &str) -> Result<KeyEntry, Error> {
+    let contents = fs::read_to_string(path).map_err(|e| Kind::InvalidKey.context(e))?;
+    let file: KeystoreFile = serde_json::from_str(&contents).map_err(|e| Kind::InvalidKey.context(e))?;
+    let raw = keystore::decrypt(&file, password)?;
+    key_entry_from_raw_bytes(&raw)
+}
+
+/// Serialize a `KeyEntry` to the raw bytes persisted in an encrypted keystore file:
+/// an algorithm tag byte followed by the algorithm-specific private key material
+/// (the private key scalar and BIP-32 chain code for secp256k1, or the raw secret
+/// scalar for ed25519).
+fn key_entry_to_raw_bytes(key: &KeyEntry) -> Vec<u8> {
+    match key {
+        KeyEntry::Secp256k1 { private_key, .. } => {
+            let mut bytes = vec![SECP256K1_TAG];
+            bytes.extend_from_slice(&private_key.private_key.to_bytes());
+            bytes.extend_from_slice(&private_key.chain_code[..]);
+            bytes
+        }
+        KeyEntry::Ed25519 { private_key, .. } => {
+            let mut bytes = vec![ED25519_TAG];
+            bytes.extend_from_slice(private_key);
+            bytes
+        }
+    }
+}
+
+/// Reconstruct a `KeyEntry` from the raw bytes read back from an encrypted keystore file.
+fn key_entry_from_raw_bytes(bytes: &[u8]) -> Result<KeyEntry, Error> {
+    match bytes.split_first() {
+        Some((&SECP256K1_TAG, rest)) if rest.len() == 64 => {
+            let secp = Secp256k1::new();
+            let secret_key = bitcoin::secp256k1::SecretKey::from_slice(&rest[0..32])
+                .map_err(|e| Kind::PrivateKey.context(e))?;
+
+            let private_key = ExtendedPrivKey {
+                network: Network::Bitcoin,
+                depth: 0,
+                parent_fingerprint: Fingerprint::default(),
+                child_number: ChildNumber::from_normal_idx(0).map_err(|e| Kind::PrivateKey.context(e))?,
+                private_key: PrivateKey {
+                    compressed: true,
+                    network: Network::Bitcoin,
+                    key: secret_key,
+                },
+                chain_code: ChainCode::from(&rest[32..64]),
+            };
+
+            let public_key = ExtendedPubKey::from_private(&secp, &private_key);
+
+            Ok(KeyEntry::Secp256k1 { public_key, private_key })
+        }
+        Some((&ED25519_TAG, rest)) if rest.len() == 32 => {
+            let mut private_key = [0u8; 32];
+            private_key.copy_from_slice(rest);
+
+            let keypair = ed25519_keypair(&private_key)?;
+
+            Ok(KeyEntry::Ed25519 { public_key: keypair.public.to_bytes(), private_key })
+        }
+        _ => Err(Kind::InvalidKey.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn derive_accounts_varies_index_not_account() {
+        let mut keyring = KeyRing::init(StoreBackend::Memory);
+        let derived = keyring
+            .derive_accounts(TEST_MNEMONIC, 0..2)
+            .expect("deriving accounts should succeed");
+
+        let mut other_keyring = KeyRing::init(StoreBackend::Memory);
+        let hd_path = standard_hd_path(COSMOS_COIN_TYPE, 0, 1).expect("path should be valid");
+        let expected_second = other_keyring
+            .add_from_mnemonic_with_path(TEST_MNEMONIC, &hd_path, KeyAlgorithm::Secp256k1)
+            .expect("adding key should succeed");
+
+        assert_eq!(derived[1], expected_second);
+    }
+
+    #[test]
+    fn derive_accounts_rejects_out_of_range_index() {
+        let mut keyring = KeyRing::init(StoreBackend::Memory);
+        let result = keyring.derive_accounts(TEST_MNEMONIC, MAX_UNHARDENED_INDEX..MAX_UNHARDENED_INDEX + 2);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn secp256k1_sign_and_verify_round_trip() {
+        let mut keyring = KeyRing::init(StoreBackend::Memory);
+        let address = keyring
+            .add_from_mnemonic(TEST_MNEMONIC)
+            .expect("adding key should succeed");
+
+        let msg = b"hello ibc".to_vec();
+        let sig = keyring
+            .sign(address.clone(), msg.clone())
+            .expect("signing should succeed");
+
+        keyring
+            .verify(address, &msg, &sig)
+            .expect("verifying a correct signature should succeed");
+    }
+
+    #[test]
+    fn verify_rejects_tampered_message() {
+        let mut keyring = KeyRing::init(StoreBackend::Memory);
+        let address = keyring
+            .add_from_mnemonic(TEST_MNEMONIC)
+            .expect("adding key should succeed");
+
+        let sig = keyring
+            .sign(address.clone(), b"right message".to_vec())
+            .expect("signing should succeed");
+
+        let result = keyring.verify(address, b"wrong message", &sig);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn generate_produces_a_usable_key() {
+        let mut keyring = KeyRing::init(StoreBackend::Memory);
+        let (phrase, address) = keyring
+            .generate(MnemonicStrength::Words12)
+            .expect("generating a mnemonic should succeed");
+
+        assert_eq!(phrase.split_whitespace().count(), 12);
+
+        let sig = keyring
+            .sign(address.clone(), b"generated key".to_vec())
+            .expect("signing with the generated key should succeed");
+
+        keyring
+            .verify(address, b"generated key", &sig)
+            .expect("verifying with the generated key should succeed");
+    }
+
+    #[test]
+    fn add_from_brain_is_deterministic() {
+        let mut keyring = KeyRing::init(StoreBackend::Memory);
+        let address = keyring
+            .add_from_brain("correct horse battery staple")
+            .expect("adding brain wallet should succeed");
+
+        let mut other_keyring = KeyRing::init(StoreBackend::Memory);
+        let other_address = other_keyring
+            .add_from_brain("correct horse battery staple")
+            .expect("adding brain wallet should succeed");
+
+        assert_eq!(address, other_address);
+    }
+
+    #[test]
+    fn ed25519_sign_and_verify_round_trip() {
+        let mut keyring = KeyRing::init(StoreBackend::Memory);
+        let hd_path = standard_hd_path(COSMOS_COIN_TYPE, 0, 0).expect("path should be valid");
+        let address = keyring
+            .add_from_mnemonic_with_path(TEST_MNEMONIC, &hd_path, KeyAlgorithm::Ed25519)
+            .expect("adding key should succeed");
+
+        let msg = b"hello tendermint".to_vec();
+        let sig = keyring
+            .sign(address.clone(), msg.clone())
+            .expect("signing should succeed");
+
+        keyring
+            .verify(address, &msg, &sig)
+            .expect("verifying a correct signature should succeed");
+    }
+
+    #[test]
+    fn ed25519_verify_rejects_tampered_message() {
+        let mut keyring = KeyRing::init(StoreBackend::Memory);
+        let hd_path = standard_hd_path(COSMOS_COIN_TYPE, 0, 0).expect("path should be valid");
+        let address = keyring
+            .add_from_mnemonic_with_path(TEST_MNEMONIC, &hd_path, KeyAlgorithm::Ed25519)
+            .expect("adding key should succeed");
+
+        let sig = keyring
+            .sign(address.clone(), b"right message".to_vec())
+            .expect("signing should succeed");
+
+        let result = keyring.verify(address, b"wrong message", &sig);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn ed25519_address_is_20_byte_tendermint_address() {
+        let mut keyring = KeyRing::init(StoreBackend::Memory);
+        let hd_path = standard_hd_path(COSMOS_COIN_TYPE, 0, 0).expect("path should be valid");
+        let address = keyring
+            .add_from_mnemonic_with_path(TEST_MNEMONIC, &hd_path, KeyAlgorithm::Ed25519)
+            .expect("adding key should succeed");
+
+        assert_eq!(address.len(), 20);
+
+        let key = keyring.get(address.clone()).expect("key should be present");
+        let public_key = match key {
+            KeyEntry::Ed25519 { public_key, .. } => public_key,
+            KeyEntry::Secp256k1 { .. } => panic!("expected an Ed25519 key entry"),
+        };
+
+        assert_eq!(ed25519_address(&public_key), address);
+    }
+}